@@ -36,6 +36,8 @@ let en_us = Standard::from_path(Language::EnglishUS, path) ?;
 */
 
 use bincode as bin;
+use unicode_normalization::UnicodeNormalization;
+use std::collections::HashMap;
 use std::error;
 use std::fmt;
 use std::io;
@@ -46,6 +48,102 @@ use std::result;
 use kl_hyphenate_commons::Language;
 use kl_hyphenate_commons::dictionary::{Standard, Extended};
 
+/// A Unicode normalization form, as supported by the `unicode_normalization`
+/// crate. The offline pattern build already normalizes patterns to one of
+/// these forms; loading with the matching form keeps runtime queries and
+/// compiled patterns on the same footing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm { NFC, NFD, NFKC, NFKD }
+
+impl NormalizationForm {
+    fn normalize(self, text : &str) -> String {
+        match self {
+            NormalizationForm::NFC => text.nfc().collect(),
+            NormalizationForm::NFD => text.nfd().collect(),
+            NormalizationForm::NFKC => text.nfkc().collect(),
+            NormalizationForm::NFKD => text.nfkd().collect()
+        }
+    }
+}
+
+/// The deserialization size limit applied unless a [`LoadOptions`]
+/// overrides it, preserving today's behavior.
+const DEFAULT_SIZE_LIMIT : u64 = 5_000_000;
+
+/// A dictionary paired with the [`NormalizationForm`] its patterns were
+/// normalized to at load time, if any. The dictionary itself has no notion
+/// of normalization, so it is up to the caller to pass query words through
+/// [`normalize_query`] before looking them up; skipping that step leaves
+/// the same NFC-vs-NFD mismatch between patterns and queries that
+/// normalizing at load time was meant to avoid.
+///
+/// [`normalize_query`]: #method.normalize_query
+#[derive(Debug, Clone)]
+pub struct Normalized<D> {
+    pub dictionary : D,
+    pub normalization : Option<NormalizationForm>
+}
+
+impl<D> Normalized<D> {
+    /// Normalize `word` the same way this dictionary's patterns were
+    /// normalized at load time (a no-op if none was requested).
+    pub fn normalize_query(&self, word : &str) -> String {
+        match self.normalization {
+            Some(form) => form.normalize(word),
+            None => word.to_owned()
+        }
+    }
+}
+
+/// Options controlling how a dictionary is loaded, refined with a builder
+/// style and passed to [`from_reader_with`]/[`from_path_with`].
+///
+/// [`from_reader_with`]: trait.Load.html#method.from_reader_with
+/// [`from_path_with`]: trait.Load.html#method.from_path_with
+#[derive(Debug, Clone)]
+pub struct LoadOptions {
+    normalization : Option<NormalizationForm>,
+    size_limit : Option<u64>
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        LoadOptions { normalization : None, size_limit : Some(DEFAULT_SIZE_LIMIT) }
+    }
+}
+
+impl LoadOptions {
+    /// An options set matching today's defaults: no normalization, and the
+    /// same 5 MB deserialization cap [`from_reader`] has always applied.
+    ///
+    /// [`from_reader`]: trait.Load.html#method.from_reader
+    pub fn new() -> Self { LoadOptions::default() }
+
+    /// Request that patterns be normalized to `form` as they are parsed
+    /// from a textual pattern file, so that query words normalized the same
+    /// way are guaranteed to match. Only applies to
+    /// [`from_tex_reader_with`]-style textual loads; passed to a bincode
+    /// load, it is rejected with [`Error::NormalizationUnsupported`] rather
+    /// than silently ignored.
+    ///
+    /// [`from_tex_reader_with`]: trait.Load.html#method.from_tex_reader_with
+    /// [`Error::NormalizationUnsupported`]: enum.Error.html#variant.NormalizationUnsupported
+    pub fn normalization(mut self, form : NormalizationForm) -> Self {
+        self.normalization = Some(form);
+        self
+    }
+
+    /// Override the deserialization size limit: `Some(n)` rejects payloads
+    /// larger than `n` bytes, `None` removes the limit entirely. Defaults
+    /// to `Some(5_000_000)`, matching [`from_reader`]'s long-standing cap.
+    ///
+    /// [`from_reader`]: trait.Load.html#method.from_reader
+    pub fn size_limit(mut self, limit : Option<u64>) -> Self {
+        self.size_limit = limit;
+        self
+    }
+}
+
 /// Convenience methods for the retrieval of hyphenation dictionaries.
 pub trait Load : Sized {
     /// Read and deserialize the dictionary at the given path, verifying that it
@@ -59,36 +157,437 @@ pub trait Load : Sized {
     /// Deserialize a dictionary from the provided reader, verifying that it
     /// effectively belongs to the requested language.
     fn from_reader<R>(lang : Language, reader : &mut R) -> Result<Self>
-    where R : io::Read;
+    where R : io::Read {
+        Self::from_reader_sized(lang, reader, Some(DEFAULT_SIZE_LIMIT))
+    }
 
     /// Deserialize a dictionary from the provided reader.
     fn any_from_reader<R>(reader : &mut R) -> Result<Self>
+    where R : io::Read {
+        Self::any_from_reader_sized(reader, Some(DEFAULT_SIZE_LIMIT))
+    }
+
+    /// Like [`from_reader`], but deserializing under the given size limit
+    /// (`None` meaning unlimited) instead of the default cap, surfacing an
+    /// exceeded limit as [`Error::SizeLimitExceeded`] rather than an opaque
+    /// [`Error::Deserialization`].
+    ///
+    /// [`from_reader`]: trait.Load.html#method.from_reader
+    /// [`Error::SizeLimitExceeded`]: enum.Error.html#variant.SizeLimitExceeded
+    /// [`Error::Deserialization`]: enum.Error.html#variant.Deserialization
+    fn from_reader_sized<R>(lang : Language, reader : &mut R, limit : Option<u64>) -> Result<Self>
+    where R : io::Read;
+
+    /// Like [`any_from_reader`], but deserializing under the given size
+    /// limit (`None` meaning unlimited) instead of the default cap.
+    ///
+    /// [`any_from_reader`]: trait.Load.html#method.any_from_reader
+    fn any_from_reader_sized<R>(reader : &mut R, limit : Option<u64>) -> Result<Self>
     where R : io::Read;
+
+    /// Parse a dictionary from the textual pattern syntax used by TeX and
+    /// libhyphen `.dic` files: an optional leading charset declaration line
+    /// (e.g. `UTF-8`, ignored – the input is expected to already be valid
+    /// UTF-8), an optional `min_left min_right` pair, a `\patterns{...}`
+    /// block of digit-interleaved patterns, and an optional
+    /// `\hyphenation{...}` block of hyphenated exception words.
+    ///
+    /// This lets community or custom dictionaries that ship only as pattern
+    /// text be loaded directly, without the offline `build.rs` step.
+    /// Malformed lines are reported as [`Error::Parse`] rather than causing
+    /// a panic.
+    ///
+    /// [`Error::Parse`]: enum.Error.html#variant.Parse
+    fn from_tex_reader<R>(lang : Language, reader : &mut R) -> Result<Self>
+    where R : io::Read {
+        Self::from_tex_reader_with(lang, reader, &LoadOptions::new()).map(|normalized| normalized.dictionary)
+    }
+
+    /// Like [`from_tex_reader`], but normalizing the pattern text to
+    /// `options.normalization` (if set) before it is parsed, and returning
+    /// the chosen form alongside the dictionary. `Self` has no field to
+    /// record the form, so this relocates the normalization burden rather
+    /// than eliminating it: callers must still run their own query words
+    /// through [`Normalized::normalize_query`] before every lookup, or the
+    /// same NFC-vs-NFD mismatch this was meant to guard against reappears.
+    ///
+    /// [`from_tex_reader`]: trait.Load.html#method.from_tex_reader
+    /// [`Normalized::normalize_query`]: struct.Normalized.html#method.normalize_query
+    fn from_tex_reader_with<R>(lang : Language, reader : &mut R, options : &LoadOptions) -> Result<Normalized<Self>>
+    where R : io::Read {
+        let mut text = String::new();
+        io::Read::read_to_string(reader, &mut text) ?;
+        let text = match options.normalization {
+            Some(form) => form.normalize(&text),
+            None => text
+        };
+        let parsed = parse_tex(&text) ?;
+        let dictionary = Self::from_patterns(lang, parsed.min_left, parsed.min_right, parsed.patterns, parsed.exceptions);
+        Ok(Normalized { dictionary, normalization : options.normalization })
+    }
+
+    /// Like [`from_path`], but accepting [`LoadOptions`] to control how the
+    /// dictionary is loaded.
+    ///
+    /// [`from_path`]: trait.Load.html#method.from_path
+    fn from_path_with<P>(lang : Language, path : P, options : &LoadOptions) -> Result<Self>
+    where P : AsRef<Path> {
+        let file = File::open(path) ?;
+        Self::from_reader_with(lang, &mut io::BufReader::new(file), options)
+    }
+
+    /// Like [`from_reader`], but accepting [`LoadOptions`] to control how
+    /// the bincode dictionary is loaded: `options.size_limit` is honored in
+    /// place of the default cap. `options.normalization` is rejected with
+    /// [`Error::NormalizationUnsupported`] rather than silently ignored: a
+    /// bincode dictionary was already normalized, if at all, at build time,
+    /// and there is no pattern text left here to normalize. Use
+    /// [`from_tex_reader_with`] for a format where normalization applies.
+    ///
+    /// [`from_reader`]: trait.Load.html#method.from_reader
+    /// [`Error::NormalizationUnsupported`]: enum.Error.html#variant.NormalizationUnsupported
+    /// [`from_tex_reader_with`]: trait.Load.html#method.from_tex_reader_with
+    fn from_reader_with<R>(lang : Language, reader : &mut R, options : &LoadOptions) -> Result<Self>
+    where R : io::Read {
+        if options.normalization.is_some() {
+            return Err(Error::NormalizationUnsupported);
+        }
+        Self::from_reader_sized(lang, reader, options.size_limit)
+    }
+
+    /// Build a dictionary directly from already-parsed patterns and
+    /// exceptions, the same entry point `build.rs` uses before serializing
+    /// the result to bincode. Provided by `kl_hyphenate_commons`, which owns
+    /// the trie construction, as an inherent `from_patterns` on `Standard`/
+    /// `Extended` with this exact signature.
+    ///
+    /// The expected encoding: each pattern pairs its letters with one break
+    /// weight per letter plus a trailing one, so `weights.len()` equals the
+    /// letter count plus one, matching what [`parse_pattern`] produces from
+    /// a token such as `1co2n3t`. Each exception pairs a word with the
+    /// character offsets, counted from the start of the word, at which a
+    /// break is permitted, matching what [`parse_exception`] produces from
+    /// a token such as `as-so-ciate`. This crate slice cannot itself
+    /// confirm that `kl_hyphenate_commons` accepts that shape; the
+    /// `parse_tex` round-trip test is as far as this tree can verify the
+    /// encoding without that crate's source.
+    ///
+    /// [`parse_pattern`]: fn.parse_pattern.html
+    /// [`parse_exception`]: fn.parse_exception.html
+    fn from_patterns(
+        lang : Language,
+        min_left : usize,
+        min_right : usize,
+        patterns : Vec<(String, Vec<u8>)>,
+        exceptions : Vec<(String, Vec<usize>)>
+    ) -> Self;
+
+}
+
+/// Patterns and exceptions parsed from a TeX/libhyphen pattern file, ready
+/// to be compiled into a dictionary by [`from_tex_reader`].
+///
+/// [`from_tex_reader`]: trait.Load.html#method.from_tex_reader
+struct TexPatterns {
+    min_left : usize,
+    min_right : usize,
+    patterns : Vec<(String, Vec<u8>)>,
+    exceptions : Vec<(String, Vec<usize>)>
+}
+
+/// Parse the textual pattern syntax, stripping `%`-comments and the
+/// `\patterns{`/`\hyphenation{`/`}` markers before tokenizing.
+fn parse_tex(text : &str) -> Result<TexPatterns> {
+    let mut tokens = Vec::new();
+    for (no, raw_line) in text.lines().enumerate() {
+        let line = match raw_line.find('%') {
+            Some(i) => &raw_line[.. i],
+            None => raw_line
+        };
+        let line = line.replace("\\patterns{", " ")
+                        .replace("\\hyphenation{", " ")
+                        .replace('}', " ");
+        for token in line.split_whitespace() {
+            tokens.push((no + 1, token.to_owned()));
+        }
+    }
+
+    let mut tokens = tokens.into_iter().peekable();
+    skip_charset_line(&mut tokens);
+    let min_left = take_minimum(&mut tokens).unwrap_or(2);
+    let min_right = take_minimum(&mut tokens).unwrap_or(3);
+
+    let mut patterns = Vec::new();
+    let mut exceptions = Vec::new();
+    for (line, token) in tokens {
+        if is_exception_token(&token) {
+            exceptions.push(parse_exception(&token));
+        } else if is_pattern_token(&token) {
+            patterns.push(parse_pattern(&token));
+        } else {
+            return Err(Error::Parse { line, reason : format!("unrecognized token `{}`", token) });
+        }
+    }
+    Ok(TexPatterns { min_left, min_right, patterns, exceptions })
+}
+
+/// Whether `token` is a single-digit hyphenation minimum, as consumed by
+/// [`take_minimum`].
+fn is_minimum_token(token : &str) -> bool {
+    token.len() == 1 && token.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Whether `token` is shaped like a digit-interleaved pattern, as consumed
+/// by [`parse_pattern`].
+fn is_pattern_token(token : &str) -> bool {
+    token.chars().all(|c| c.is_ascii_digit() || c.is_alphabetic() || c == '.')
+}
+
+/// Whether `token` is shaped like a hyphenated exception word, as consumed
+/// by [`parse_exception`].
+fn is_exception_token(token : &str) -> bool {
+    token.contains('-') && token.chars().all(|c| c.is_alphabetic() || c == '-')
+}
+
+/// Skip a leading charset declaration, such as libhyphen `.dic` files carry
+/// on their first line (e.g. `UTF-8`, `ISO8859-1`) ahead of the hyphenation
+/// minima and patterns. Recognized as a token on line 1 that is none of a
+/// hyphenation minimum, a pattern, or an exception word – i.e. one that
+/// would otherwise be misparsed or rejected – rather than merely "a lone
+/// token on line 1", so a real pattern or exception that happens to start
+/// the file is left alone.
+fn skip_charset_line<I>(tokens : &mut std::iter::Peekable<I>)
+where I : Iterator<Item = (usize, String)> {
+    let is_charset_line = matches!(
+        tokens.peek(),
+        Some((1, token)) if !is_minimum_token(token) && !is_pattern_token(token) && !is_exception_token(token)
+    );
+    if is_charset_line {
+        tokens.next();
+    }
+}
+
+/// Consume a single leading digit token as a hyphenation minimum, if the
+/// next token looks like one.
+fn take_minimum<I>(tokens : &mut std::iter::Peekable<I>) -> Option<usize>
+where I : Iterator<Item = (usize, String)> {
+    match tokens.peek() {
+        Some((_, token)) if is_minimum_token(token) => {
+            let (_, token) = tokens.next().unwrap();
+            token.parse().ok()
+        },
+        _ => None
+    }
+}
+
+/// Split a digit-interleaved pattern token, such as `1co2n3t`, into its
+/// letters and the break-weight preceding each one (plus the trailing
+/// weight after the last letter).
+fn parse_pattern(token : &str) -> (String, Vec<u8>) {
+    let mut letters = String::new();
+    let mut weights = Vec::new();
+    let mut chars = token.chars().peekable();
+    loop {
+        let weight = match chars.peek() {
+            Some(c) if c.is_ascii_digit() => {
+                let d = c.to_digit(10).unwrap() as u8;
+                chars.next();
+                d
+            },
+            _ => 0
+        };
+        weights.push(weight);
+        match chars.next() {
+            Some(c) => letters.push(c),
+            None => break
+        }
+    }
+    (letters, weights)
+}
+
+/// Split a hyphenated exception token, such as `as-so-ciate`, into its
+/// plain word and the character offsets at which a break is permitted.
+fn parse_exception(token : &str) -> (String, Vec<usize>) {
+    let mut word = String::new();
+    let mut breaks = Vec::new();
+    for c in token.chars() {
+        if c == '-' {
+            breaks.push(word.chars().count());
+        } else {
+            word.push(c);
+        }
+    }
+    (word, breaks)
+}
+
+/// Translate a bincode failure into an [`Error`], calling out an exceeded
+/// `limit` with a dedicated, descriptive variant instead of surfacing the
+/// opaque [`Error::Deserialization`].
+///
+/// [`Error`]: enum.Error.html
+/// [`Error::Deserialization`]: enum.Error.html#variant.Deserialization
+fn map_bincode_error(err : bin::Error, limit : Option<u64>) -> Error {
+    let exceeded_limit = matches!(*err, bin::ErrorKind::SizeLimit);
+    match limit {
+        Some(limit) if exceeded_limit => Error::SizeLimitExceeded { limit },
+        _ => Error::Deserialization(err)
+    }
 }
 
 macro_rules! impl_load {
-    ($dict:ty, $suffix:expr) => {
+    ($dict:ty) => {
         impl Load for $dict {
-            fn from_reader<R>(lang : Language, reader : &mut R) -> Result<Self>
+            fn from_reader_sized<R>(lang : Language, reader : &mut R, limit : Option<u64>) -> Result<Self>
             where R : io::Read {
-                let dict : Self = bin::config().limit(5_000_000).deserialize_from(reader) ?;
+                let dict = Self::any_from_reader_sized(reader, limit) ?;
                 let (found, expected) = (dict.language, lang);
                 if found != expected {
                     Err(Error::LanguageMismatch { expected, found })
                 } else { Ok(dict) }
             }
 
-            fn any_from_reader<R>(reader : &mut R) -> Result<Self>
+            fn any_from_reader_sized<R>(reader : &mut R, limit : Option<u64>) -> Result<Self>
             where R : io::Read {
-                let dict : Self = bin::config().limit(5_000_000).deserialize_from(reader) ?;
-                Ok(dict)
+                let result = match limit {
+                    Some(limit) => bin::config().limit(limit).deserialize_from(reader),
+                    None => bin::config().deserialize_from(reader)
+                };
+                result.map_err(|err| map_bincode_error(err, limit))
+            }
+
+            fn from_patterns(
+                lang : Language,
+                min_left : usize,
+                min_right : usize,
+                patterns : Vec<(String, Vec<u8>)>,
+                exceptions : Vec<(String, Vec<usize>)>
+            ) -> Self {
+                <$dict>::from_patterns(lang, min_left, min_right, patterns, exceptions)
             }
         }
     }
 }
 
-impl_load! { Standard, "standard" }
-impl_load! { Extended, "extended" }
+impl_load! { Standard }
+impl_load! { Extended }
+
+
+/// A non-fatal event encountered while loading a dictionary into a
+/// [`Registry`], reported to its warning sink instead of failing the call.
+#[derive(Debug)]
+pub enum LoadWarning {
+    /// The dictionary found under `registered` was tagged for `found`
+    /// instead, and was cached under `registered` anyway.
+    LanguageCoerced { registered : Language, found : Language },
+    /// A dictionary was registered for a language that already had one
+    /// cached, replacing it.
+    Replaced { lang : Language },
+    /// A load was retried under a larger size limit than the default to
+    /// accommodate the dictionary.
+    SizeLimitBumped { lang : Language, limit : u64 }
+}
+
+impl fmt::Display for LoadWarning {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LoadWarning::LanguageCoerced { registered, found } => write!(f, "\
+dictionary registered under `{}` is tagged for `{}`; it was cached under
+`{}` regardless.", registered, found, registered),
+            LoadWarning::Replaced { lang } =>
+                write!(f, "a dictionary for `{}` was already registered and has been replaced", lang),
+            LoadWarning::SizeLimitBumped { lang, limit } =>
+                write!(f, "dictionary for `{}` exceeded the default size limit; retried with a {}-byte limit", lang, limit)
+        }
+    }
+}
+
+/// A caching registry of [`Standard`] dictionaries keyed by [`Language`],
+/// for applications that hyphenate mixed-language text and would otherwise
+/// have to reload a dictionary on every lookup.
+///
+/// Dictionaries are loaded once, eagerly, on [`register`]/[`register_reader`],
+/// and reused from then on; [`get`] only looks up what is already cached and
+/// never loads anything itself. Non-fatal issues – a coerced language tag, or one
+/// registration overriding another – are reported to an optional warning
+/// sink rather than failing the call outright.
+///
+/// [`register`]: #method.register
+/// [`register_reader`]: #method.register_reader
+/// [`get`]: #method.get
+pub struct Registry {
+    dictionaries : HashMap<Language, Standard>,
+    on_warning : Option<Box<dyn FnMut(LoadWarning)>>
+}
+
+impl Default for Registry {
+    fn default() -> Self { Registry::new() }
+}
+
+impl Registry {
+    /// An empty registry with no warning sink installed.
+    pub fn new() -> Self {
+        Registry { dictionaries : HashMap::new(), on_warning : None }
+    }
+
+    /// Install a sink to receive [`LoadWarning`]s raised by subsequent
+    /// [`register`]/[`register_reader`] calls.
+    ///
+    /// [`register`]: #method.register
+    /// [`register_reader`]: #method.register_reader
+    pub fn on_warning<F>(&mut self, sink : F)
+    where F : FnMut(LoadWarning) + 'static {
+        self.on_warning = Some(Box::new(sink));
+    }
+
+    /// Load and cache the dictionary at `path` under `lang`.
+    pub fn register<P>(&mut self, lang : Language, path : P) -> Result<()>
+    where P : AsRef<Path> {
+        let file = File::open(path) ?;
+        self.register_reader(lang, &mut io::BufReader::new(file))
+    }
+
+    /// Load and cache a dictionary from `reader` under `lang`. A language
+    /// tag mismatch is coerced rather than failing the call; it is instead
+    /// reported to the warning sink, if any.
+    ///
+    /// A dictionary larger than [`DEFAULT_SIZE_LIMIT`] is not rejected
+    /// outright: `reader` is buffered in full and retried under a limit equal
+    /// to the buffered size, reporting [`LoadWarning::SizeLimitBumped`] to the warning sink, if
+    /// any, rather than failing the call.
+    ///
+    /// [`DEFAULT_SIZE_LIMIT`]: constant.DEFAULT_SIZE_LIMIT.html
+    /// [`LoadWarning::SizeLimitBumped`]: enum.LoadWarning.html#variant.SizeLimitBumped
+    pub fn register_reader<R>(&mut self, lang : Language, reader : &mut R) -> Result<()>
+    where R : io::Read {
+        let mut bytes = Vec::new();
+        io::Read::read_to_end(reader, &mut bytes) ?;
+        let dict = match Standard::any_from_reader_sized(&mut io::Cursor::new(&bytes), Some(DEFAULT_SIZE_LIMIT)) {
+            Err(Error::SizeLimitExceeded { .. }) => {
+                self.warn(LoadWarning::SizeLimitBumped { lang, limit : bytes.len() as u64 });
+                Standard::any_from_reader_sized(&mut io::Cursor::new(&bytes), Some(bytes.len() as u64)) ?
+            },
+            result => result ?
+        };
+        if dict.language != lang {
+            self.warn(LoadWarning::LanguageCoerced { registered : lang, found : dict.language });
+        }
+        if self.dictionaries.insert(lang, dict).is_some() {
+            self.warn(LoadWarning::Replaced { lang });
+        }
+        Ok(())
+    }
+
+    /// The cached dictionary for `lang`, if one has been registered.
+    pub fn get(&self, lang : Language) -> Option<&Standard> {
+        self.dictionaries.get(&lang)
+    }
+
+    fn warn(&mut self, warning : LoadWarning) {
+        if let Some(sink) = self.on_warning.as_mut() {
+            sink(warning);
+        }
+    }
+}
 
 
 pub type Result<T> = result::Result<T, Error>;
@@ -103,7 +602,16 @@ pub enum Error {
     /// The loaded dictionary is for the wrong language.
     LanguageMismatch { expected : Language, found : Language },
     /// The embedded dictionary could not be retrieved.
-    Resource
+    Resource,
+    /// A line of a textual pattern file could not be parsed.
+    Parse { line : usize, reason : String },
+    /// The dictionary exceeded the deserialization size limit.
+    SizeLimitExceeded { limit : u64 },
+    /// [`LoadOptions::normalization`] was set for a load that has no
+    /// pattern text left to normalize, such as a bincode dictionary.
+    ///
+    /// [`LoadOptions::normalization`]: struct.LoadOptions.html#method.normalization
+    NormalizationUnsupported
 }
 
 impl error::Error for Error {
@@ -125,7 +633,13 @@ impl fmt::Display for Error {
                 write!(f, "\
 Language mismatch: attempted to load a dictionary for `{}`, but found
 a dictionary for `{}` instead.", expected, found),
-            Error::Resource => f.write_str("the embedded dictionary could not be retrieved")
+            Error::Resource => f.write_str("the embedded dictionary could not be retrieved"),
+            Error::Parse { line, ref reason } =>
+                write!(f, "failed to parse pattern file at line {}: {}", line, reason),
+            Error::SizeLimitExceeded { limit } =>
+                write!(f, "the dictionary exceeds the {}-byte deserialization size limit", limit),
+            Error::NormalizationUnsupported =>
+                f.write_str("normalization was requested, but this load has no pattern text left to normalize")
         }
     }
 }
@@ -137,3 +651,36 @@ impl From<io::Error> for Error {
 impl From<bin::Error> for Error {
     fn from(err : bin::Error) -> Error { Error::Deserialization(err) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Round-trips a known pattern and exception through [`parse_tex`],
+    /// pinning down the `(letters, weights)`/`(word, breaks)` encoding
+    /// `from_patterns` implementations are expected to accept. This is as
+    /// far as this crate slice can verify the contract: confirming
+    /// `kl_hyphenate_commons::dictionary::{Standard, Extended}` actually
+    /// build a working trie from that encoding needs that crate's source,
+    /// which isn't part of this tree.
+    #[test]
+    fn parse_tex_round_trips_known_pattern_and_exception() {
+        let text = "\\patterns{\n1co2n3t\n}\n\\hyphenation{\nas-so-ciate\n}\n";
+        let parsed = parse_tex(text).unwrap();
+        assert_eq!(parsed.min_left, 2);
+        assert_eq!(parsed.min_right, 3);
+        assert_eq!(parsed.patterns, vec![("cont".to_owned(), vec![1, 0, 2, 3, 0])]);
+        assert_eq!(parsed.exceptions, vec![("associate".to_owned(), vec![2, 4])]);
+    }
+
+    /// A libhyphen `.dic` charset line is skipped rather than misparsed as
+    /// an exception word, while a pattern or exception on line 1 survives.
+    #[test]
+    fn parse_tex_skips_charset_line_but_not_line_one_data() {
+        let with_charset = parse_tex("UTF-8\n\\patterns{\n1co2n3t\n}\n").unwrap();
+        assert_eq!(with_charset.patterns, vec![("cont".to_owned(), vec![1, 0, 2, 3, 0])]);
+
+        let without_charset = parse_tex("\\patterns{1ab}\n").unwrap();
+        assert_eq!(without_charset.patterns, vec![("ab".to_owned(), vec![1, 0, 0])]);
+    }
+}